@@ -2,102 +2,155 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Which cgroup hierarchy layout the host is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CgroupVersion {
+    /// Single unified hierarchy (`cgroup2`), e.g. `cpu.stat`, `memory.current`.
+    V2,
+    /// Split per-controller hierarchies, e.g. `cpuacct.usage`, `memory.usage_in_bytes`.
+    V1,
+}
+
+/// The resolved cgroup paths for a single container.
+///
+/// On cgroup v2 every field points at the same unified directory. On cgroup
+/// v1 each field points at the matching controller's own hierarchy, since
+/// `cpu`/`cpuacct`, `memory`, `blkio`, `pids` and `hugetlb` are mounted
+/// separately.
+#[derive(Debug, Clone)]
+pub struct ContainerCgroup {
+    pub version: CgroupVersion,
+    pub cpu: PathBuf,
+    pub memory: PathBuf,
+    pub blkio: PathBuf,
+    pub pids: PathBuf,
+    /// `None` when the host doesn't mount a `hugetlb` controller at all (common
+    /// on cgroup v1 hosts where hugepage accounting is disabled). Hugepage
+    /// stats are optional, unlike CPU/memory/block I/O, so this must not fail
+    /// cgroup resolution the way `find_in_controller` does for the others.
+    pub hugetlb: Option<PathBuf>,
+}
+
 pub struct CgroupManager {
     cgroup_root: PathBuf,
+    version: CgroupVersion,
 }
 
 impl CgroupManager {
     pub fn new() -> Result<Self> {
         let cgroup_root = PathBuf::from("/sys/fs/cgroup");
-        
+
         if !cgroup_root.exists() {
-            anyhow::bail!("cgroupv2 not found at /sys/fs/cgroup");
+            anyhow::bail!("cgroup filesystem not found at /sys/fs/cgroup");
+        }
+
+        let version = if cgroup_root.join("cgroup.controllers").exists() {
+            CgroupVersion::V2
+        } else {
+            CgroupVersion::V1
+        };
+
+        Ok(Self { cgroup_root, version })
+    }
+
+    /// Locates a container's cgroup directories. `container_id` must already be
+    /// resolved to the full container ID (see `DockerClient::inspect`).
+    pub fn find_container_cgroup(&self, container_id: &str) -> Result<ContainerCgroup> {
+        match self.version {
+            CgroupVersion::V2 => {
+                let path = self.find_in_hierarchy(&self.cgroup_root, container_id)?;
+                Ok(ContainerCgroup {
+                    version: CgroupVersion::V2,
+                    cpu: path.clone(),
+                    memory: path.clone(),
+                    blkio: path.clone(),
+                    pids: path.clone(),
+                    hugetlb: Some(path),
+                })
+            }
+            CgroupVersion::V1 => {
+                let cpu = self.find_in_controller("cpu,cpuacct", container_id)
+                    .or_else(|_| self.find_in_controller("cpuacct", container_id))?;
+                let memory = self.find_in_controller("memory", container_id)?;
+                let blkio = self.find_in_controller("blkio", container_id)?;
+                let pids = self.find_in_controller("pids", container_id)?;
+                // Unlike the other controllers, a missing/disabled hugetlb
+                // controller shouldn't fail the whole lookup.
+                let hugetlb = self.find_in_controller("hugetlb", container_id).ok();
+
+                Ok(ContainerCgroup { version: CgroupVersion::V1, cpu, memory, blkio, pids, hugetlb })
+            }
+        }
+    }
+
+    fn find_in_controller(&self, controller: &str, container_id: &str) -> Result<PathBuf> {
+        let controller_root = self.cgroup_root.join(controller);
+        if !controller_root.exists() {
+            anyhow::bail!("cgroup v1 controller {} not mounted", controller);
         }
-        
-        Ok(Self { cgroup_root })
+        self.find_in_hierarchy(&controller_root, container_id)
     }
-    
-    pub fn find_container_cgroup(&self, container_name_or_id: &str) -> Result<PathBuf> {
-        // First try to get container ID from Docker
-        let container_id = self.resolve_container_id(container_name_or_id)?;
-        
-        // Look for the container in Docker's cgroup hierarchy
-        let system_slice_path = self.cgroup_root.join("system.slice");
-        
+
+    fn find_in_hierarchy(&self, root: &Path, container_id: &str) -> Result<PathBuf> {
+        // Look for the container directly under system.slice.
+        let system_slice_path = root.join("system.slice");
+
         if system_slice_path.exists() {
-            // Look for container-specific cgroup directly in system.slice
             let container_path = system_slice_path.join(format!("docker-{}.scope", container_id));
             if container_path.exists() {
                 return Ok(container_path);
             }
-            
+
             // Also try with short container ID (first 12 chars)
             let short_id = &container_id[..12.min(container_id.len())];
             let container_path_short = system_slice_path.join(format!("docker-{}.scope", short_id));
             if container_path_short.exists() {
                 return Ok(container_path_short);
             }
-            
+
             // Search recursively in system.slice
-            if let Ok(path) = self.search_for_container(&system_slice_path, &container_id) {
+            if let Ok(path) = self.search_for_container(&system_slice_path, container_id) {
                 return Ok(path);
             }
         }
-        
+
         // Alternative: search in user.slice for rootless Docker
-        let user_slice_path = self.cgroup_root.join("user.slice");
+        let user_slice_path = root.join("user.slice");
         if user_slice_path.exists() {
-            if let Ok(path) = self.search_for_container(&user_slice_path, &container_id) {
+            if let Ok(path) = self.search_for_container(&user_slice_path, container_id) {
                 return Ok(path);
             }
         }
-        
-        anyhow::bail!("Container {} not found in cgroup hierarchy", container_name_or_id)
-    }
-    
-    fn resolve_container_id(&self, name_or_id: &str) -> Result<String> {
-        // Try using docker inspect to get full container ID
-        let output = std::process::Command::new("docker")
-            .args(["inspect", "--format", "{{.Id}}", name_or_id])
-            .output()
-            .context("Failed to run docker inspect")?;
-            
-        if output.status.success() {
-            let id = String::from_utf8(output.stdout)?
-                .trim()
-                .to_string();
-            return Ok(id);
-        }
-        
-        // If docker command fails, assume it's already a container ID
-        Ok(name_or_id.to_string())
+
+        anyhow::bail!("Container {} not found under {}", container_id, root.display())
     }
-    
+
     fn search_for_container(&self, base_path: &Path, container_id: &str) -> Result<PathBuf> {
         let entries = fs::read_dir(base_path)
             .context(format!("Failed to read directory: {:?}", base_path))?;
-            
+
         for entry in entries {
             let entry = entry?;
             let path = entry.path();
-            
+
             if path.is_dir() {
                 let name = path.file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or("");
-                    
+
                 // Check if this directory contains our container ID
-                if name.contains(container_id) || name.contains(&container_id[..12]) {
+                let short_id = &container_id[..12.min(container_id.len())];
+                if name.contains(container_id) || name.contains(short_id) {
                     return Ok(path);
                 }
-                
+
                 // Recursively search subdirectories
                 if let Ok(found) = self.search_for_container(&path, container_id) {
                     return Ok(found);
                 }
             }
         }
-        
+
         anyhow::bail!("Container not found in {}", base_path.display())
     }
 }
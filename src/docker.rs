@@ -0,0 +1,129 @@
+use anyhow::{Context, Result};
+use hyper::body::Buf;
+use hyper::{Body, Client, Method, Request};
+use hyperlocal::{UnixClientExt, UnixConnector, Uri as UnixUri};
+use serde::Deserialize;
+
+const DOCKER_SOCK: &str = "/var/run/docker.sock";
+
+#[derive(Debug, Deserialize)]
+struct InspectState {
+    #[serde(rename = "Pid")]
+    pid: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct InspectResponse {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "State")]
+    state: InspectState,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListEntry {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "Names")]
+    names: Vec<String>,
+}
+
+/// The subset of `GET /containers/{id}/json` we care about: the full container
+/// ID (for resolving cgroup paths) and the main process PID (for reading
+/// `/proc/<pid>/net/dev`).
+#[derive(Debug, Clone)]
+pub struct ContainerInfo {
+    pub id: String,
+    pub pid: u64,
+}
+
+/// An entry from `GET /containers/json`, used for name-based discovery.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ContainerSummary {
+    pub id: String,
+    pub name: String,
+}
+
+/// A small async client for the Docker Engine API over its UNIX socket,
+/// replacing the `docker` CLI subprocess previously spawned per sample.
+pub struct DockerClient {
+    client: Client<UnixConnector, Body>,
+}
+
+impl DockerClient {
+    pub fn new() -> Self {
+        Self { client: Client::unix() }
+    }
+
+    /// Fetches ID and PID for a container in a single round-trip.
+    pub async fn inspect(&self, container_name_or_id: &str) -> Result<ContainerInfo> {
+        let uri: hyper::Uri = UnixUri::new(DOCKER_SOCK, &format!("/containers/{}/json", container_name_or_id)).into();
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(Body::empty())?;
+
+        let resp = self.client.request(req).await
+            .context("Failed to reach Docker daemon over /var/run/docker.sock")?;
+
+        if !resp.status().is_success() {
+            anyhow::bail!("docker inspect {} failed with status {}", container_name_or_id, resp.status());
+        }
+
+        let body = hyper::body::aggregate(resp).await?;
+        let parsed: InspectResponse = serde_json::from_reader(body.reader())?;
+
+        Ok(ContainerInfo {
+            id: parsed.id,
+            pid: parsed.state.pid.max(0) as u64,
+        })
+    }
+
+    /// Lists running containers, optionally filtered by name. Backs a future
+    /// `--all` mode that auto-discovers containers instead of requiring
+    /// `config.toml` to enumerate them.
+    #[allow(dead_code)]
+    pub async fn list(&self, name_filter: Option<&str>) -> Result<Vec<ContainerSummary>> {
+        let path = match name_filter {
+            Some(name) => format!("/containers/json?filters={}", percent_encode(&format!(r#"{{"name":["{}"]}}"#, name))),
+            None => "/containers/json".to_string(),
+        };
+
+        let uri: hyper::Uri = UnixUri::new(DOCKER_SOCK, &path).into();
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(Body::empty())?;
+
+        let resp = self.client.request(req).await
+            .context("Failed to reach Docker daemon over /var/run/docker.sock")?;
+
+        if !resp.status().is_success() {
+            anyhow::bail!("docker list containers failed with status {}", resp.status());
+        }
+
+        let body = hyper::body::aggregate(resp).await?;
+        let parsed: Vec<ListEntry> = serde_json::from_reader(body.reader())?;
+
+        Ok(parsed
+            .into_iter()
+            .map(|entry| ContainerSummary {
+                id: entry.id,
+                name: entry.names.into_iter().next().unwrap_or_default().trim_start_matches('/').to_string(),
+            })
+            .collect())
+    }
+}
+
+/// Minimal percent-encoding for the small JSON `filters` query parameter above.
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(b as char),
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
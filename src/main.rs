@@ -1,21 +1,25 @@
 use anyhow::Result;
 use clap::Parser;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::time::Duration;
 use tokio::time;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 
+mod cgroup;
 mod config;
+mod docker;
+mod metrics;
 mod monitor;
-mod cgroup;
+mod retry;
 
 use config::Config;
 use monitor::ContainerMonitor;
 
 #[derive(Parser)]
 #[command(name = "vessel")]
-#[command(about = "Monitor Docker container resource usage via cgroupv2")]
+#[command(about = "Monitor Docker container resource usage via cgroups (v1 or v2)")]
 struct Cli {
     /// Configuration file path
     #[arg(short, long, default_value = "config.toml")]
@@ -32,6 +36,10 @@ struct Cli {
     /// Output JSON file path
     #[arg(short, long, default_value = "vessel_stats.json")]
     output: PathBuf,
+
+    /// Address to serve Prometheus metrics on, e.g. 0.0.0.0:9100
+    #[arg(long)]
+    metrics_addr: Option<SocketAddr>,
 }
 
 #[tokio::main]
@@ -55,6 +63,15 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
     
+    if let Some(addr) = cli.metrics_addr {
+        let handle = metrics::install_recorder()?;
+        tokio::spawn(async move {
+            if let Err(e) = metrics::serve(addr, handle).await {
+                eprintln!("Metrics server error: {}", e);
+            }
+        });
+    }
+
     let mut monitor = ContainerMonitor::new()?;
     let interval = Duration::from_secs(cli.interval);
     
@@ -79,14 +96,20 @@ async fn main() -> Result<()> {
                     if !first_entry {
                         file.write_all(b",\n").await?;
                     }
-                    
+
                     let json = stats.to_json()?;
                     file.write_all(format!("  {}", json).as_bytes()).await?;
                     file.flush().await?;
-                    
+
                     first_entry = false;
-                    
-                    println!("Updated stats for {}", container);
+
+                    metrics::record_stats(&stats);
+
+                    if stats.running {
+                        println!("Updated stats for {}", container);
+                    } else {
+                        println!("{} is not running, recorded a zeroed sample", container);
+                    }
                 }
                 Err(e) => {
                     eprintln!("Error monitoring {}: {}", container, e);
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use axum::extract::State;
+use axum::routing::get;
+use axum::Router;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use std::net::SocketAddr;
+
+use crate::monitor::ContainerStats;
+
+/// Installs the global Prometheus recorder and returns a handle that can render
+/// the current registry as text-exposition output.
+pub fn install_recorder() -> Result<PrometheusHandle> {
+    let handle = PrometheusBuilder::new().install_recorder()?;
+    Ok(handle)
+}
+
+#[derive(Clone)]
+struct MetricsState {
+    handle: PrometheusHandle,
+}
+
+/// Serves `/metrics` on `addr` until the process exits.
+pub async fn serve(addr: SocketAddr, handle: PrometheusHandle) -> Result<()> {
+    let state = MetricsState { handle };
+    let app = Router::new()
+        .route("/metrics", get(render_metrics))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    println!("Serving Prometheus metrics on http://{}/metrics", addr);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn render_metrics(State(state): State<MetricsState>) -> String {
+    state.handle.render()
+}
+
+/// Updates the Prometheus registry with the latest sample for a container.
+pub fn record_stats(stats: &ContainerStats) {
+    let labels = [("container", stats.name.clone())];
+
+    metrics::gauge!("vessel_cpu_percentage", &labels).set(stats.cpu_percentage);
+    metrics::gauge!("vessel_memory_usage_bytes", &labels).set(stats.memory_usage as f64);
+    metrics::gauge!("vessel_memory_limit_bytes", &labels).set(stats.memory_limit as f64);
+    metrics::gauge!("vessel_block_read_bytes", &labels).set(stats.block_read as f64);
+    metrics::gauge!("vessel_block_write_bytes", &labels).set(stats.block_write as f64);
+    metrics::gauge!("vessel_network_rx_bytes", &labels).set(stats.network_rx as f64);
+    metrics::gauge!("vessel_network_tx_bytes", &labels).set(stats.network_tx as f64);
+    metrics::gauge!("vessel_network_rx_bytes_per_second", &labels).set(stats.network_rx_rate);
+    metrics::gauge!("vessel_network_tx_bytes_per_second", &labels).set(stats.network_tx_rate);
+    metrics::gauge!("vessel_cpu_nr_throttled", &labels).set(stats.cpu_nr_throttled as f64);
+    metrics::gauge!("vessel_cpu_throttled_usec", &labels).set(stats.cpu_throttled_usec as f64);
+    metrics::gauge!("vessel_pids_current", &labels).set(stats.pids_current as f64);
+    if let Some(pids_max) = stats.pids_max {
+        metrics::gauge!("vessel_pids_max", &labels).set(pids_max as f64);
+    }
+
+    for hugepage in &stats.hugepage_usage {
+        let labels = [("container", stats.name.clone()), ("size", hugepage.size.clone())];
+        metrics::gauge!("vessel_hugetlb_usage_bytes", &labels).set(hugepage.usage_bytes as f64);
+    }
+
+    for device in &stats.block_io_devices {
+        let labels = [("container", stats.name.clone()), ("device", device.major_minor.clone())];
+        metrics::gauge!("vessel_block_device_read_bytes", &labels).set(device.rbytes as f64);
+        metrics::gauge!("vessel_block_device_write_bytes", &labels).set(device.wbytes as f64);
+        metrics::gauge!("vessel_block_device_read_ios", &labels).set(device.rios as f64);
+        metrics::gauge!("vessel_block_device_write_ios", &labels).set(device.wios as f64);
+    }
+}
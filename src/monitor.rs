@@ -5,22 +5,57 @@ use std::path::Path;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
-use crate::cgroup::CgroupManager;
+use crate::cgroup::{CgroupManager, CgroupVersion, ContainerCgroup};
+use crate::docker::DockerClient;
+use crate::retry::read_to_string_with_retry;
+
+/// How many times to retry a cgroup stat file read before giving up, to ride
+/// out the brief window where the file disappears mid container start/stop.
+const CGROUP_READ_RETRIES: u32 = 5;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockIoDevice {
+    /// The device's `MAJ:MIN` identifier, e.g. "8:0".
+    pub major_minor: String,
+    pub rbytes: u64,
+    pub wbytes: u64,
+    pub rios: u64,
+    pub wios: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HugepageUsage {
+    /// Human-readable page size, e.g. "2MB" or "1GB".
+    pub size: String,
+    pub usage_bytes: u64,
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ContainerStats {
     pub id: String,
     pub name: String,
+    /// `false` when the container's cgroup couldn't be found or one of its
+    /// stat files couldn't be read (e.g. mid start/stop); the remaining
+    /// numeric fields are zeroed in that case.
+    pub running: bool,
     pub cpu_percentage: f64,
     pub cpu_usage_usec: u64,
     pub system_usage_usec: u64,
+    pub cpu_nr_throttled: u64,
+    pub cpu_throttled_usec: u64,
     pub memory_usage: u64,
     pub memory_limit: u64,
     pub memory_percentage: f64,
     pub network_rx: u64,
     pub network_tx: u64,
+    pub network_rx_rate: f64,
+    pub network_tx_rate: f64,
     pub block_read: u64,
     pub block_write: u64,
+    pub block_io_devices: Vec<BlockIoDevice>,
+    pub pids_current: u64,
+    pub pids_max: Option<u64>,
+    pub hugepage_usage: Vec<HugepageUsage>,
     pub timestamp: DateTime<Utc>,
 }
 
@@ -28,7 +63,37 @@ impl ContainerStats {
     pub fn to_json(&self) -> Result<String> {
         Ok(serde_json::to_string_pretty(self)?)
     }
-    
+
+    /// A zeroed sample recorded when a container's cgroup or its stat files are
+    /// missing, e.g. during start/stop, rather than failing the whole monitoring tick.
+    fn not_running(id: String, name: &str) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            running: false,
+            cpu_percentage: 0.0,
+            cpu_usage_usec: 0,
+            system_usage_usec: 0,
+            cpu_nr_throttled: 0,
+            cpu_throttled_usec: 0,
+            memory_usage: 0,
+            memory_limit: 0,
+            memory_percentage: 0.0,
+            network_rx: 0,
+            network_tx: 0,
+            network_rx_rate: 0.0,
+            network_tx_rate: 0.0,
+            block_read: 0,
+            block_write: 0,
+            block_io_devices: Vec::new(),
+            pids_current: 0,
+            pids_max: None,
+            hugepage_usage: Vec::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+
     // pub fn format_output(&self) -> String {
     //     let mem_usage_mb = self.memory_usage as f64 / 1024.0 / 1024.0;
     //     let mem_limit_mb = self.memory_limit as f64 / 1024.0 / 1024.0;
@@ -54,85 +119,166 @@ impl ContainerStats {
 
 pub struct ContainerMonitor {
     cgroup_manager: CgroupManager,
+    docker: DockerClient,
     previous_stats: HashMap<String, (u64, u64)>, // (cpu_usage, timestamp_ns)
+    previous_network: HashMap<String, (u64, u64, u64)>, // (rx_bytes, tx_bytes, timestamp_ns)
 }
 
 impl ContainerMonitor {
     pub fn new() -> Result<Self> {
         let cgroup_manager = CgroupManager::new()?;
-        
+
         Ok(Self {
             cgroup_manager,
+            docker: DockerClient::new(),
             previous_stats: HashMap::new(),
+            previous_network: HashMap::new(),
         })
     }
-    
+
     pub async fn get_stats(&mut self, container_name: &str) -> Result<ContainerStats> {
-        let cgroup_path = self.cgroup_manager.find_container_cgroup(container_name)?;
-        let container_id = self.get_container_id(container_name)?;
-        
-        let (cpu_usage_percent, cpu_usage_usec, system_usage_usec) = self.get_cpu_usage(&cgroup_path, container_name)?;
-        let (memory_usage, memory_limit, memory_percent) = self.get_memory_usage(&cgroup_path)?;
-        let (net_rx, net_tx) = self.get_network_usage()?;
-        let (block_read, block_write) = self.get_block_io_usage(&cgroup_path)?;
-        
+        // One round-trip to the Docker daemon gives us both the full container
+        // ID (for resolving the cgroup path) and the main PID (for network stats).
+        let info = self.docker.inspect(container_name).await.ok();
+        let container_id = info.as_ref()
+            .map(|info| info.id.clone())
+            .unwrap_or_else(|| container_name.to_string());
+        let pid = info.as_ref().map(|info| info.pid).unwrap_or(0);
+
+        // Cgroup directories and their stat files come and go while a container
+        // is starting or stopping. Treat that window as "not running" instead of
+        // failing the whole sample, so the monitoring loop stays up.
+        let cgroup = match self.cgroup_manager.find_container_cgroup(&container_id) {
+            Ok(cgroup) => cgroup,
+            Err(e) => {
+                eprintln!("{}: {}", container_name, e);
+                return Ok(ContainerStats::not_running(container_id, container_name));
+            }
+        };
+
+        match self.collect_stats(&cgroup, &container_id, container_name, pid).await {
+            Ok(stats) => Ok(stats),
+            Err(e) => {
+                eprintln!("{}: {}", container_name, e);
+                Ok(ContainerStats::not_running(container_id, container_name))
+            }
+        }
+    }
+
+    async fn collect_stats(
+        &mut self,
+        cgroup: &ContainerCgroup,
+        container_id: &str,
+        container_name: &str,
+        pid: u64,
+    ) -> Result<ContainerStats> {
+        let (cpu_usage_percent, cpu_usage_usec, system_usage_usec, cpu_nr_throttled, cpu_throttled_usec) =
+            self.get_cpu_usage(cgroup, container_name).await?;
+        let (memory_usage, memory_limit, memory_percent) = self.get_memory_usage(cgroup).await?;
+        let (net_rx, net_tx, net_rx_rate, net_tx_rate) = self.get_network_usage(container_name, pid)?;
+        let (block_read, block_write, block_io_devices) = self.get_block_io_usage(cgroup)?;
+        let (pids_current, pids_max) = self.get_pids_usage(cgroup)?;
+        let hugepage_usage = self.get_hugepage_usage(cgroup)?;
+
         Ok(ContainerStats {
-            id: container_id,
+            id: container_id.to_string(),
             name: container_name.to_string(),
+            running: true,
             cpu_percentage: cpu_usage_percent,
             cpu_usage_usec,
             system_usage_usec,
+            cpu_nr_throttled,
+            cpu_throttled_usec,
             memory_usage,
             memory_limit,
             memory_percentage: memory_percent,
             network_rx: net_rx,
             network_tx: net_tx,
+            network_rx_rate: net_rx_rate,
+            network_tx_rate: net_tx_rate,
             block_read,
             block_write,
+            block_io_devices,
+            pids_current,
+            pids_max,
+            hugepage_usage,
             timestamp: Utc::now(),
         })
     }
-    
-    fn get_container_id(&self, container_name: &str) -> Result<String> {
-        // Try using docker inspect to get full container ID
-        let output = std::process::Command::new("docker")
-            .args(["inspect", "--format", "{{.Id}}", container_name])
-            .output()
-            .context("Failed to run docker inspect")?;
-            
-        if output.status.success() {
-            let id = String::from_utf8(output.stdout)?
-                .trim()
-                .to_string();
-            return Ok(id);
-        }
-        
-        // If docker command fails, assume it's already a container ID
-        Ok(container_name.to_string())
-    }
-    
-    fn get_cpu_usage(&mut self, cgroup_path: &Path, container_name: &str) -> Result<(f64, u64, u64)> {
-        let cpu_stat_path = cgroup_path.join("cpu.stat");
-        let content = fs::read_to_string(&cpu_stat_path)
-            .context(format!("Failed to read {:?}", cpu_stat_path))?;
-        
-        let mut usage_usec = 0u64;
-        let mut system_usec = 0u64;
-        
-        for line in content.lines() {
-            if line.starts_with("usage_usec ") {
-                usage_usec = line.split_whitespace()
-                    .nth(1)
-                    .and_then(|s| s.parse().ok())
+
+    async fn get_cpu_usage(&mut self, cgroup: &ContainerCgroup, container_name: &str) -> Result<(f64, u64, u64, u64, u64)> {
+        let (usage_usec, system_usec, nr_throttled, throttled_usec) = match cgroup.version {
+            CgroupVersion::V2 => {
+                let cpu_stat_path = cgroup.cpu.join("cpu.stat");
+                let content = read_to_string_with_retry(&cpu_stat_path, CGROUP_READ_RETRIES).await?;
+
+                let mut usage_usec = 0u64;
+                let mut system_usec = 0u64;
+                let mut nr_throttled = 0u64;
+                let mut throttled_usec = 0u64;
+
+                for line in content.lines() {
+                    if line.starts_with("usage_usec ") {
+                        usage_usec = line.split_whitespace()
+                            .nth(1)
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or(0);
+                    } else if line.starts_with("system_usec ") {
+                        system_usec = line.split_whitespace()
+                            .nth(1)
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or(0);
+                    } else if line.starts_with("nr_throttled ") {
+                        nr_throttled = line.split_whitespace()
+                            .nth(1)
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or(0);
+                    } else if line.starts_with("throttled_usec ") {
+                        throttled_usec = line.split_whitespace()
+                            .nth(1)
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or(0);
+                    }
+                }
+
+                (usage_usec, system_usec, nr_throttled, throttled_usec)
+            }
+            CgroupVersion::V1 => {
+                let usage_path = cgroup.cpu.join("cpuacct.usage");
+                let usage_ns = read_to_string_with_retry(&usage_path, CGROUP_READ_RETRIES).await?
+                    .trim()
+                    .parse::<u64>()
                     .unwrap_or(0);
-            } else if line.starts_with("system_usec ") {
-                system_usec = line.split_whitespace()
-                    .nth(1)
-                    .and_then(|s| s.parse().ok())
+
+                let system_ns = fs::read_to_string(cgroup.cpu.join("cpuacct.usage_sys"))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<u64>().ok())
                     .unwrap_or(0);
+
+                // cgroup v1's cpu.stat reports nr_throttled the same way, but the
+                // throttled time field is named `throttled_time` and in nanoseconds.
+                let mut nr_throttled = 0u64;
+                let mut throttled_ns = 0u64;
+                if let Ok(content) = fs::read_to_string(cgroup.cpu.join("cpu.stat")) {
+                    for line in content.lines() {
+                        if line.starts_with("nr_throttled ") {
+                            nr_throttled = line.split_whitespace()
+                                .nth(1)
+                                .and_then(|s| s.parse().ok())
+                                .unwrap_or(0);
+                        } else if line.starts_with("throttled_time ") {
+                            throttled_ns = line.split_whitespace()
+                                .nth(1)
+                                .and_then(|s| s.parse().ok())
+                                .unwrap_or(0);
+                        }
+                    }
+                }
+
+                (usage_ns / 1000, system_ns / 1000, nr_throttled, throttled_ns / 1000)
             }
-        }
-        
+        };
+
         let current_time = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -152,74 +298,297 @@ impl ContainerMonitor {
         };
         
         self.previous_stats.insert(container_name.to_string(), (usage_usec, current_time));
-        Ok((cpu_percent, usage_usec, system_usec))
+        Ok((cpu_percent, usage_usec, system_usec, nr_throttled, throttled_usec))
     }
     
-    fn get_memory_usage(&self, cgroup_path: &Path) -> Result<(u64, u64, f64)> {
-        let memory_current_path = cgroup_path.join("memory.current");
-        let memory_max_path = cgroup_path.join("memory.max");
-        
-        let current = fs::read_to_string(&memory_current_path)
-            .context(format!("Failed to read {:?}", memory_current_path))?
+    async fn get_memory_usage(&self, cgroup: &ContainerCgroup) -> Result<(u64, u64, f64)> {
+        let (current_path, max_path) = match cgroup.version {
+            CgroupVersion::V2 => (cgroup.memory.join("memory.current"), cgroup.memory.join("memory.max")),
+            CgroupVersion::V1 => (cgroup.memory.join("memory.usage_in_bytes"), cgroup.memory.join("memory.limit_in_bytes")),
+        };
+
+        let current = read_to_string_with_retry(&current_path, CGROUP_READ_RETRIES).await?
             .trim()
             .parse::<u64>()?;
-        
-        let max_content = fs::read_to_string(&memory_max_path)
-            .context(format!("Failed to read {:?}", memory_max_path))?;
-        
-        let max = if max_content.trim() == "max" {
-            // Get system memory as fallback
+
+        let max_content = read_to_string_with_retry(&max_path, CGROUP_READ_RETRIES).await?;
+        let max_trimmed = max_content.trim();
+
+        // cgroup v2 uses the literal "max"; cgroup v1 uses a huge sentinel value
+        // (commonly i64::MAX rounded down to a page boundary) to mean "unlimited".
+        let max = if max_trimmed == "max" {
             self.get_system_memory().unwrap_or(0)
         } else {
-            max_content.trim().parse::<u64>()?
+            match max_trimmed.parse::<u64>() {
+                Ok(v) if v > (1u64 << 62) => self.get_system_memory().unwrap_or(0),
+                Ok(v) => v,
+                Err(_) => self.get_system_memory().unwrap_or(0),
+            }
         };
-        
+
         let percentage = if max > 0 {
             (current as f64 / max as f64) * 100.0
         } else {
             0.0
         };
-        
+
         Ok((current, max, percentage))
     }
-    
-    fn get_network_usage(&self) -> Result<(u64, u64)> {
-        // Network stats are typically in /proc/net/dev for the container's network namespace
-        // For now, return zeros as network monitoring requires more complex setup
-        Ok((0, 0))
+
+    fn get_network_usage(&mut self, container_name: &str, pid: u64) -> Result<(u64, u64, f64, f64)> {
+        if pid == 0 {
+            eprintln!("Warning: container {} has no running PID, skipping network stats", container_name);
+            return Ok((0, 0, 0.0, 0.0));
+        }
+
+        let net_dev_path = format!("/proc/{}/net/dev", pid);
+        let content = match fs::read_to_string(&net_dev_path) {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("Warning: failed to read {}: {}", net_dev_path, e);
+                return Ok((0, 0, 0.0, 0.0));
+            }
+        };
+
+        let mut rx_bytes = 0u64;
+        let mut tx_bytes = 0u64;
+
+        // The first two lines are headers; each remaining line is "iface: rx... tx...".
+        for line in content.lines().skip(2) {
+            let Some((iface, counters)) = line.split_once(':') else {
+                continue;
+            };
+
+            if iface.trim() == "lo" {
+                continue;
+            }
+
+            let fields: Vec<&str> = counters.split_whitespace().collect();
+            // Column 0 is rx bytes, column 8 is tx bytes (see `man proc` for /proc/net/dev).
+            if fields.len() < 9 {
+                continue;
+            }
+
+            rx_bytes += fields[0].parse::<u64>().unwrap_or(0);
+            tx_bytes += fields[8].parse::<u64>().unwrap_or(0);
+        }
+
+        let current_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let (rx_rate, tx_rate) = if let Some((prev_rx, prev_tx, prev_time)) = self.previous_network.get(container_name) {
+            let time_diff_secs = (current_time - prev_time) as f64 / 1_000_000_000.0;
+
+            if time_diff_secs > 0.0 {
+                (
+                    rx_bytes.saturating_sub(*prev_rx) as f64 / time_diff_secs,
+                    tx_bytes.saturating_sub(*prev_tx) as f64 / time_diff_secs,
+                )
+            } else {
+                (0.0, 0.0)
+            }
+        } else {
+            (0.0, 0.0)
+        };
+
+        self.previous_network.insert(container_name.to_string(), (rx_bytes, tx_bytes, current_time));
+
+        Ok((rx_bytes, tx_bytes, rx_rate, tx_rate))
     }
-    
-    fn get_block_io_usage(&self, cgroup_path: &Path) -> Result<(u64, u64)> {
-        let io_stat_path = cgroup_path.join("io.stat");
-        
+
+    fn get_block_io_usage(&self, cgroup: &ContainerCgroup) -> Result<(u64, u64, Vec<BlockIoDevice>)> {
+        let devices = match cgroup.version {
+            CgroupVersion::V2 => self.get_block_io_devices_v2(&cgroup.blkio)?,
+            CgroupVersion::V1 => self.get_block_io_devices_v1(&cgroup.blkio)?,
+        };
+
+        let read_bytes = devices.iter().map(|d| d.rbytes).sum();
+        let write_bytes = devices.iter().map(|d| d.wbytes).sum();
+
+        Ok((read_bytes, write_bytes, devices))
+    }
+
+    fn get_block_io_devices_v2(&self, blkio_path: &Path) -> Result<Vec<BlockIoDevice>> {
+        let io_stat_path = blkio_path.join("io.stat");
+
         if !io_stat_path.exists() {
-            return Ok((0, 0));
+            return Ok(Vec::new());
         }
-        
+
         let content = fs::read_to_string(&io_stat_path)
             .context(format!("Failed to read {:?}", io_stat_path))?;
-        
-        let mut read_bytes = 0u64;
-        let mut write_bytes = 0u64;
-        
+
+        let mut devices = Vec::new();
+
         for line in content.lines() {
             let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 3 {
-                for chunk in parts[1..].chunks(2) {
-                    if chunk.len() == 2 {
-                        match chunk[0] {
-                            "rbytes" => read_bytes += chunk[1].parse::<u64>().unwrap_or(0),
-                            "wbytes" => write_bytes += chunk[1].parse::<u64>().unwrap_or(0),
-                            _ => {}
-                        }
+            if parts.len() < 2 {
+                continue;
+            }
+
+            let mut device = BlockIoDevice {
+                major_minor: parts[0].to_string(),
+                rbytes: 0,
+                wbytes: 0,
+                rios: 0,
+                wios: 0,
+            };
+
+            for chunk in parts[1..].chunks(2) {
+                if chunk.len() == 2 {
+                    let value = chunk[1].parse::<u64>().unwrap_or(0);
+                    match chunk[0] {
+                        "rbytes" => device.rbytes = value,
+                        "wbytes" => device.wbytes = value,
+                        "rios" => device.rios = value,
+                        "wios" => device.wios = value,
+                        _ => {}
                     }
                 }
             }
+
+            devices.push(device);
         }
-        
-        Ok((read_bytes, write_bytes))
+
+        Ok(devices)
     }
-    
+
+    fn get_block_io_devices_v1(&self, blkio_path: &Path) -> Result<Vec<BlockIoDevice>> {
+        let bytes_by_device = self.read_blkio_throttle_file(blkio_path, "blkio.throttle.io_service_bytes")?;
+        let ios_by_device = self.read_blkio_throttle_file(blkio_path, "blkio.throttle.io_serviced")?;
+
+        let mut devices: HashMap<String, BlockIoDevice> = HashMap::new();
+
+        for (major_minor, (read, write)) in bytes_by_device {
+            let entry = devices.entry(major_minor.clone()).or_insert_with(|| BlockIoDevice {
+                major_minor,
+                rbytes: 0,
+                wbytes: 0,
+                rios: 0,
+                wios: 0,
+            });
+            entry.rbytes = read;
+            entry.wbytes = write;
+        }
+
+        for (major_minor, (read, write)) in ios_by_device {
+            let entry = devices.entry(major_minor.clone()).or_insert_with(|| BlockIoDevice {
+                major_minor,
+                rbytes: 0,
+                wbytes: 0,
+                rios: 0,
+                wios: 0,
+            });
+            entry.rios = read;
+            entry.wios = write;
+        }
+
+        Ok(devices.into_values().collect())
+    }
+
+    /// Parses a `blkio.throttle.io_service*` file into per-device (Read, Write) pairs.
+    /// Lines look like "<major>:<minor> Read <n>", "<major>:<minor> Write <n>", with
+    /// additional Sync/Async/Total lines we don't need.
+    fn read_blkio_throttle_file(&self, blkio_path: &Path, file_name: &str) -> Result<HashMap<String, (u64, u64)>> {
+        let path = blkio_path.join(file_name);
+
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .context(format!("Failed to read {:?}", path))?;
+
+        let mut by_device: HashMap<String, (u64, u64)> = HashMap::new();
+
+        for line in content.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() == 3 {
+                let value = parts[2].parse::<u64>().unwrap_or(0);
+                let entry = by_device.entry(parts[0].to_string()).or_insert((0, 0));
+                match parts[1] {
+                    "Read" => entry.0 = value,
+                    "Write" => entry.1 = value,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(by_device)
+    }
+
+    fn get_pids_usage(&self, cgroup: &ContainerCgroup) -> Result<(u64, Option<u64>)> {
+        let current = fs::read_to_string(cgroup.pids.join("pids.current"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+
+        let max = fs::read_to_string(cgroup.pids.join("pids.max"))
+            .ok()
+            .and_then(|s| {
+                let s = s.trim();
+                if s == "max" {
+                    None
+                } else {
+                    s.parse::<u64>().ok()
+                }
+            });
+
+        Ok((current, max))
+    }
+
+    fn get_hugepage_usage(&self, cgroup: &ContainerCgroup) -> Result<Vec<HugepageUsage>> {
+        let Some(hugetlb_path) = &cgroup.hugetlb else {
+            // No hugetlb controller mounted on this host at all.
+            return Ok(Vec::new());
+        };
+
+        let usage_file_suffix = match cgroup.version {
+            CgroupVersion::V2 => ".current",
+            CgroupVersion::V1 => ".usage_in_bytes",
+        };
+
+        let entries = match fs::read_dir(hugetlb_path) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let mut usage = Vec::new();
+
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else { continue };
+
+            let Some(size_token) = file_name
+                .strip_prefix("hugetlb.")
+                .and_then(|rest| rest.strip_suffix(usage_file_suffix))
+            else {
+                continue;
+            };
+
+            // Skip reservation accounting files such as `hugetlb.2MB.rsvd.current`,
+            // whose middle token is not just a page size.
+            if size_token.contains('.') {
+                continue;
+            }
+
+            let bytes = fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|s| s.trim().parse::<u64>().ok())
+                .unwrap_or(0);
+
+            usage.push(HugepageUsage {
+                size: hugepage_size_label(size_token),
+                usage_bytes: bytes,
+            });
+        }
+
+        usage.sort_by(|a, b| a.size.cmp(&b.size));
+        Ok(usage)
+    }
+
     fn get_system_memory(&self) -> Result<u64> {
         let meminfo = fs::read_to_string("/proc/meminfo")?;
         for line in meminfo.lines() {
@@ -234,3 +603,12 @@ impl ContainerMonitor {
         Ok(0)
     }
 }
+
+/// Extracts the moniker a cgroup hugetlb controller file already encodes in
+/// its name, e.g. `hugetlb.2MB.current` / `hugetlb.1GB.usage_in_bytes` ->
+/// "2MB" / "1GB" (matching the "<n>KB"/"<n>MB"/"<n>GB" style youki uses).
+/// Both cgroup v1 and v2 name these files with the size pre-formatted, so no
+/// further derivation from a raw byte/kB count is needed.
+fn hugepage_size_label(size_token: &str) -> String {
+    size_token.to_string()
+}
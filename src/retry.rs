@@ -0,0 +1,34 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+const BASE_DELAY: Duration = Duration::from_millis(10);
+
+/// Retries `f` with exponential backoff starting at 10ms and doubling each
+/// attempt, up to `max_retries` times. Modeled on youki's `delete_with_retry`:
+/// cgroup directories and their stat files can vanish and reappear while a
+/// container is starting or stopping, so a single missed read shouldn't be
+/// treated as fatal. Sleeps on `tokio::time`, not `std::thread`, so a
+/// blocked call doesn't stall the async runtime's worker thread.
+pub async fn retry_with_backoff<T>(max_retries: u32, mut f: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut attempt = 0;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= max_retries => return Err(e),
+            Err(_) => {
+                tokio::time::sleep(BASE_DELAY * 2u32.pow(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Reads a cgroup stat file, retrying through the transient window where the
+/// container is starting or stopping and the file doesn't exist yet/anymore.
+pub async fn read_to_string_with_retry(path: &Path, max_retries: u32) -> Result<String> {
+    retry_with_backoff(max_retries, || {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))
+    })
+    .await
+}